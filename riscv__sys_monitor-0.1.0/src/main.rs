@@ -1,34 +1,330 @@
-use sysinfo::{System, SystemExt, CpuExt, DiskExt};
+use sysinfo::{System, SystemExt, CpuExt, DiskExt, NetworksExt, NetworkExt, ProcessExt, ComponentExt};
 use chrono::Local;
-use std::{thread, time::Duration};
+use std::{thread, time::{Duration, Instant}};
 use structopt::StructOpt;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::fs;
 
 #[derive(StructOpt)]
 #[structopt(name = "riscv_sysmon", about = "System monitor for RISC-V SBCs on Linux")]
 struct Cli {
     #[structopt(short = "i", long = "interval", default_value = "1", help = "Update interval in seconds")]
     interval: u64,
+
+    #[structopt(long = "include-loopback", help = "Include the loopback interface in network stats")]
+    include_loopback: bool,
+
+    #[structopt(long = "top", default_value = "5", help = "Number of top processes to display")]
+    top: usize,
+
+    #[structopt(long = "sort", default_value = "cpu", help = "Sort top processes by 'cpu' or 'mem'")]
+    sort: SortBy,
+
+    #[structopt(long = "once", help = "Refresh once, print, and exit (one-shot health check)")]
+    once: bool,
+
+    #[structopt(long = "cpu-threshold", help = "Alert if CPU usage exceeds this percentage")]
+    cpu_threshold: Option<f64>,
+
+    #[structopt(long = "mem-threshold", help = "Alert if memory usage exceeds this percentage")]
+    mem_threshold: Option<f64>,
+
+    #[structopt(long = "disk-threshold", help = "Alert if any disk's usage exceeds this percentage")]
+    disk_threshold: Option<f64>,
+
+    #[structopt(long = "format", default_value = "text", help = "Output format: text, json, csv, or prometheus")]
+    format: Format,
+
+    #[structopt(long = "fahrenheit", help = "Report temperatures in Fahrenheit instead of Celsius")]
+    fahrenheit: bool,
+}
+
+/// Output backend for the collected metrics.
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+    Prometheus,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "prometheus" | "prom" => Ok(Format::Prometheus),
+            other => Err(format!("invalid format '{}' (expected text, json, csv, or prometheus)", other)),
+        }
+    }
+}
+
+/// How the top-process view is ordered.
+#[derive(Clone, Copy)]
+enum SortBy {
+    Cpu,
+    Mem,
+}
+
+impl std::str::FromStr for SortBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "cpu" => Ok(SortBy::Cpu),
+            "mem" | "memory" => Ok(SortBy::Mem),
+            other => Err(format!("invalid sort key '{}' (expected 'cpu' or 'mem')", other)),
+        }
+    }
 }
 
 fn main() {
     let args = Cli::from_args();
     let update_interval = Duration::from_secs(args.interval);
 
-    println!("Starting riscv_sysmon...");
-    let mut sys = match System::new_all() {
-        sys => sys,
-    };
+    // Keep the banner out of machine-readable streams: it is printed only in text mode.
+    if args.format == Format::Text {
+        println!("Starting riscv_sysmon...");
+    }
+    let mut sys = System::new_all();
+
+    let mut disk_io = DiskIoSampler::new();
+    let mut net_io = NetIoSampler::new();
+    let mut csv_header_printed = false;
 
     loop {
         match refresh_system(&mut sys) {
-            Ok(_) => print_system_info(&sys),
+            Ok(_) => {
+                // In one-shot mode there is no previous tick, so sysinfo has no
+                // interval over which to compute per-core CPU deltas and would
+                // report ~0%. Warm up with a short pause and a second CPU
+                // refresh so the printed CPU% (and `--cpu-threshold`) is real.
+                if args.once {
+                    thread::sleep(Duration::from_millis(200));
+                    sys.refresh_cpu();
+                }
+
+                let rates = disk_io.sample();
+                let net_rates = net_io.sample(&sys);
+                let metrics = collect_metrics(&sys);
+                match args.format {
+                    Format::Text => {
+                        print_system_info(&sys, &rates);
+                        print_network_info(&sys, &net_rates, args.include_loopback);
+                        print_process_info(&sys, args.top, args.sort);
+                        print_thermal_info(&sys, args.fahrenheit);
+                    }
+                    Format::Json => println!("{}", metrics.to_json()),
+                    Format::Csv => {
+                        if !csv_header_printed {
+                            println!("{}", Metrics::csv_header());
+                            csv_header_printed = true;
+                        }
+                        println!("{}", metrics.to_csv());
+                    }
+                    Format::Prometheus => print!("{}", metrics.to_prometheus()),
+                }
+                let tripped = check_thresholds(&sys, &args);
+                if args.once {
+                    // Exit code summarizes how many thresholds tripped (capped at 255).
+                    std::process::exit(tripped.min(255) as i32);
+                }
+            }
             Err(e) => eprintln!("Error refreshing system info: {}", e),
         }
         thread::sleep(update_interval);
     }
 }
 
+/// Compares the current metrics against any configured thresholds, printing a
+/// labeled `ALERT` line to stderr for each one exceeded and returning the
+/// number tripped. Routing alerts to stderr keeps them out of the structured
+/// stdout stream (`--format json|csv|prometheus`), so scrapers stay parseable.
+fn check_thresholds(sys: &System, args: &Cli) -> usize {
+    let mut tripped = 0;
+
+    if let Some(limit) = args.cpu_threshold {
+        let cpu_usage = sys.cpus().iter().map(|cpu| cpu.cpu_usage()).sum::<f32>() as f64
+            / sys.cpus().len() as f64;
+        if cpu_usage > limit {
+            eprintln!("ALERT: CPU usage {:.2}% exceeds threshold {:.2}%", cpu_usage, limit);
+            tripped += 1;
+        }
+    }
+
+    if let Some(limit) = args.mem_threshold {
+        let total = sys.total_memory();
+        if total > 0 {
+            let mem_usage = sys.used_memory() as f64 / total as f64 * 100.0;
+            if mem_usage > limit {
+                eprintln!("ALERT: Memory usage {:.2}% exceeds threshold {:.2}%", mem_usage, limit);
+                tripped += 1;
+            }
+        }
+    }
+
+    if let Some(limit) = args.disk_threshold {
+        for disk in sys.disks() {
+            let total = disk.total_space();
+            if total == 0 {
+                continue;
+            }
+            let used = total - disk.available_space();
+            let disk_usage = used as f64 / total as f64 * 100.0;
+            if disk_usage > limit {
+                eprintln!(
+                    "ALERT: Disk {} usage {:.2}% exceeds threshold {:.2}%",
+                    disk.name().to_string_lossy(),
+                    disk_usage,
+                    limit,
+                );
+                tripped += 1;
+            }
+        }
+    }
+
+    tripped
+}
+
+/// Read/write byte-per-second rates for a single block device.
+struct DiskIoRate {
+    read_bytes_per_sec: f64,
+    write_bytes_per_sec: f64,
+}
+
+/// Samples cumulative per-device sector counters from `/proc/diskstats` and
+/// turns the delta between successive ticks into read/write throughput.
+struct DiskIoSampler {
+    /// Previous `(sectors_read, sectors_written)` per device name.
+    prev: HashMap<String, (u64, u64)>,
+    last_sampled: Option<Instant>,
+}
+
+impl DiskIoSampler {
+    fn new() -> Self {
+        DiskIoSampler { prev: HashMap::new(), last_sampled: None }
+    }
+
+    /// Reads `/proc/diskstats` and returns the throughput for every device
+    /// that was also present in the previous snapshot. Newly-appeared devices
+    /// (and any counter that went backwards, i.e. wraparound) are skipped for
+    /// this tick and seeded for the next one.
+    fn sample(&mut self) -> HashMap<String, DiskIoRate> {
+        let mut rates = HashMap::new();
+
+        let now = Instant::now();
+        let elapsed = self.last_sampled.map(|t| now.duration_since(t).as_secs_f64());
+        self.last_sampled = Some(now);
+
+        let contents = match fs::read_to_string("/proc/diskstats") {
+            Ok(c) => c,
+            Err(_) => return rates,
+        };
+
+        let mut current = HashMap::new();
+        for line in contents.lines() {
+            // Fields: major minor name reads_completed reads_merged sectors_read
+            // ms_reading writes_completed writes_merged sectors_written ...
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 10 {
+                continue;
+            }
+            let name = fields[2].to_string();
+            let sectors_read: u64 = match fields[5].parse() {
+                Ok(v) => v,
+                Err(_) => continue,
+            };
+            let sectors_written: u64 = match fields[9].parse() {
+                Ok(v) => v,
+                Err(_) => continue,
+            };
+
+            if let (Some(elapsed), Some(&(prev_read, prev_written))) =
+                (elapsed, self.prev.get(&name))
+            {
+                // Skip counter wraparound and zero/negative intervals.
+                if elapsed > 0.0 && sectors_read >= prev_read && sectors_written >= prev_written {
+                    rates.insert(
+                        name.clone(),
+                        DiskIoRate {
+                            read_bytes_per_sec: (sectors_read - prev_read) as f64 * 512.0 / elapsed,
+                            write_bytes_per_sec: (sectors_written - prev_written) as f64 * 512.0 / elapsed,
+                        },
+                    );
+                }
+            }
+
+            current.insert(name, (sectors_read, sectors_written));
+        }
+
+        self.prev = current;
+        rates
+    }
+}
+
+/// Received/transmitted byte-per-second rates for a single interface.
+struct NetIoRate {
+    rx_bytes_per_sec: f64,
+    tx_bytes_per_sec: f64,
+}
+
+/// Samples cumulative received/transmitted byte totals from `sys.networks()`
+/// and turns the delta between successive ticks into up/down throughput.
+struct NetIoSampler {
+    /// Previous `(total_received, total_transmitted)` per interface name.
+    prev: HashMap<String, (u64, u64)>,
+    last_sampled: Option<Instant>,
+}
+
+impl NetIoSampler {
+    fn new() -> Self {
+        NetIoSampler { prev: HashMap::new(), last_sampled: None }
+    }
+
+    /// Returns the throughput for every interface present in the previous
+    /// snapshot. Newly-appeared interfaces (and any counter that went
+    /// backwards) are skipped for this tick and seeded for the next one.
+    fn sample(&mut self, sys: &System) -> HashMap<String, NetIoRate> {
+        let mut rates = HashMap::new();
+
+        let now = Instant::now();
+        let elapsed = self.last_sampled.map(|t| now.duration_since(t).as_secs_f64());
+        self.last_sampled = Some(now);
+
+        let mut current = HashMap::new();
+        for (name, data) in sys.networks().iter() {
+            let rx = data.total_received();
+            let tx = data.total_transmitted();
+
+            if let (Some(elapsed), Some(&(prev_rx, prev_tx))) = (elapsed, self.prev.get(name)) {
+                if elapsed > 0.0 && rx >= prev_rx && tx >= prev_tx {
+                    rates.insert(
+                        name.clone(),
+                        NetIoRate {
+                            rx_bytes_per_sec: (rx - prev_rx) as f64 / elapsed,
+                            tx_bytes_per_sec: (tx - prev_tx) as f64 / elapsed,
+                        },
+                    );
+                }
+            }
+
+            current.insert(name.clone(), (rx, tx));
+        }
+
+        self.prev = current;
+        rates
+    }
+}
+
+/// Maps a `sysinfo` disk name (e.g. `/dev/sda1`) to the `/proc/diskstats`
+/// device name (e.g. `sda1`) so throughput can be matched to capacity.
+fn diskstats_device_name(disk_name: &str) -> &str {
+    disk_name.strip_prefix("/dev/").unwrap_or(disk_name)
+}
+
 /// Refreshes all system information, returns an error if the refresh fails
 fn refresh_system(sys: &mut System) -> Result<(), String> {
     sys.refresh_all();
@@ -53,8 +349,150 @@ fn format_size(size_in_bytes: u64) -> String {
     }
 }
 
+/// Formats a byte-per-second rate, reusing [`format_size`] with a "/s" suffix.
+fn format_rate(bytes_per_sec: f64) -> String {
+    format!("{}/s", format_size(bytes_per_sec as u64))
+}
+
+/// Capacity metrics for a single disk, in bytes.
+struct DiskMetric {
+    device: String,
+    used_bytes: u64,
+    free_bytes: u64,
+    total_bytes: u64,
+}
+
+/// A snapshot of the headline system metrics for one refresh tick, decoupled
+/// from any particular output format.
+struct Metrics {
+    timestamp: String,
+    cpu_percent: f64,
+    mem_used_bytes: u64,
+    mem_total_bytes: u64,
+    disks: Vec<DiskMetric>,
+}
+
+/// Gathers the serializable metrics for the current tick, applying the same
+/// disk de-duplication rules as the human-readable view.
+fn collect_metrics(sys: &System) -> Metrics {
+    let cpu_percent = sys.cpus().iter().map(|cpu| cpu.cpu_usage()).sum::<f32>() as f64
+        / sys.cpus().len() as f64;
+
+    let mut disks = Vec::new();
+    let mut seen_disks = HashSet::new();
+    for disk in sys.disks() {
+        let name = disk.name().to_string_lossy();
+        let total = disk.total_space();
+        if name != "none" && total > 0 && seen_disks.insert((name.to_string(), total)) {
+            let free = disk.available_space();
+            disks.push(DiskMetric {
+                device: name.to_string(),
+                used_bytes: total - free,
+                free_bytes: free,
+                total_bytes: total,
+            });
+        }
+    }
+
+    Metrics {
+        timestamp: Local::now().format("%Y-%m-%dT%H:%M:%S").to_string(),
+        cpu_percent,
+        mem_used_bytes: sys.used_memory(),
+        mem_total_bytes: sys.total_memory(),
+        disks,
+    }
+}
+
+/// Escapes a string for embedding in a JSON or Prometheus label value.
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+impl Metrics {
+    /// One newline-delimited JSON object per tick, suitable for streaming.
+    fn to_json(&self) -> String {
+        let disks: Vec<String> = self
+            .disks
+            .iter()
+            .map(|d| {
+                format!(
+                    "{{\"device\":\"{}\",\"used_bytes\":{},\"free_bytes\":{},\"total_bytes\":{}}}",
+                    escape(&d.device), d.used_bytes, d.free_bytes, d.total_bytes,
+                )
+            })
+            .collect();
+        format!(
+            "{{\"timestamp\":\"{}\",\"cpu_percent\":{:.2},\"memory_used_bytes\":{},\"memory_total_bytes\":{},\"disks\":[{}]}}",
+            self.timestamp, self.cpu_percent, self.mem_used_bytes, self.mem_total_bytes, disks.join(","),
+        )
+    }
+
+    /// Header row emitted once before any CSV rows.
+    fn csv_header() -> &'static str {
+        "timestamp,cpu_percent,memory_used_bytes,memory_total_bytes,device,disk_used_bytes,disk_total_bytes"
+    }
+
+    /// One CSV row per disk (or a single row with empty disk columns when no
+    /// disks are reported), repeating the shared fields on each line.
+    fn to_csv(&self) -> String {
+        if self.disks.is_empty() {
+            return format!(
+                "{},{:.2},{},{},,,",
+                self.timestamp, self.cpu_percent, self.mem_used_bytes, self.mem_total_bytes,
+            );
+        }
+        self.disks
+            .iter()
+            .map(|d| {
+                format!(
+                    "{},{:.2},{},{},{},{},{}",
+                    self.timestamp, self.cpu_percent, self.mem_used_bytes, self.mem_total_bytes,
+                    d.device, d.used_bytes, d.total_bytes,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Prometheus text exposition format, ready to be scraped.
+    fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        out.push_str("# HELP riscv_sysmon_cpu_usage_percent Aggregate CPU usage across all cores.\n");
+        out.push_str("# TYPE riscv_sysmon_cpu_usage_percent gauge\n");
+        out.push_str(&format!("riscv_sysmon_cpu_usage_percent {:.2}\n", self.cpu_percent));
+
+        out.push_str("# HELP riscv_sysmon_memory_used_bytes Memory currently in use, in bytes.\n");
+        out.push_str("# TYPE riscv_sysmon_memory_used_bytes gauge\n");
+        out.push_str(&format!("riscv_sysmon_memory_used_bytes {}\n", self.mem_used_bytes));
+
+        out.push_str("# HELP riscv_sysmon_memory_total_bytes Total memory, in bytes.\n");
+        out.push_str("# TYPE riscv_sysmon_memory_total_bytes gauge\n");
+        out.push_str(&format!("riscv_sysmon_memory_total_bytes {}\n", self.mem_total_bytes));
+
+        out.push_str("# HELP riscv_sysmon_disk_used_bytes Disk space in use per device, in bytes.\n");
+        out.push_str("# TYPE riscv_sysmon_disk_used_bytes gauge\n");
+        for d in &self.disks {
+            out.push_str(&format!(
+                "riscv_sysmon_disk_used_bytes{{device=\"{}\"}} {}\n",
+                escape(&d.device), d.used_bytes,
+            ));
+        }
+
+        out.push_str("# HELP riscv_sysmon_disk_total_bytes Total disk space per device, in bytes.\n");
+        out.push_str("# TYPE riscv_sysmon_disk_total_bytes gauge\n");
+        for d in &self.disks {
+            out.push_str(&format!(
+                "riscv_sysmon_disk_total_bytes{{device=\"{}\"}} {}\n",
+                escape(&d.device), d.total_bytes,
+            ));
+        }
+
+        out
+    }
+}
+
 /// Prints CPU, memory, and disk information from the system
-fn print_system_info(sys: &System) {
+fn print_system_info(sys: &System, disk_io: &HashMap<String, DiskIoRate>) {
     let local_time = Local::now();
     println!("\nSystem Metrics - {}", local_time.format("%Y-%m-%d %H:%M:%S"));
     println!("---------------------------");
@@ -74,6 +512,8 @@ fn print_system_info(sys: &System) {
     let mut total_used = 0u64;
     let mut total_free = 0u64;
     let mut total_space = 0u64;
+    let mut total_read_rate = 0.0f64;
+    let mut total_write_rate = 0.0f64;
 
     for disk in sys.disks() {
         let name = disk.name().to_string_lossy();
@@ -87,13 +527,25 @@ fn print_system_info(sys: &System) {
             total_free += available_space_mb;
             total_space += total_space_mb;
 
-            println!(
+            print!(
                 "Disk: {:<15} | Used: {:>8} | Free: {:>8} | Total: {:>8}",
                 name,
                 format_size(used_space_mb),
                 format_size(available_space_mb),
                 format_size(total_space_mb),
             );
+
+            // Append live throughput if we have a diskstats sample for this device.
+            if let Some(rate) = disk_io.get(diskstats_device_name(&name)) {
+                total_read_rate += rate.read_bytes_per_sec;
+                total_write_rate += rate.write_bytes_per_sec;
+                print!(
+                    " | Read: {:>10} | Write: {:>10}",
+                    format_rate(rate.read_bytes_per_sec),
+                    format_rate(rate.write_bytes_per_sec),
+                );
+            }
+            println!();
         }
     }
 
@@ -104,6 +556,196 @@ fn print_system_info(sys: &System) {
         format_size(total_free),
         format_size(total_space),
     );
+    if !disk_io.is_empty() {
+        println!(
+            "Total Disk I/O:   Read: {} | Write: {}",
+            format_rate(total_read_rate),
+            format_rate(total_write_rate),
+        );
+    }
+}
+
+/// Returns true for loopback interfaces, which are hidden unless explicitly
+/// requested.
+fn is_loopback(name: &str) -> bool {
+    name == "lo" || name.starts_with("lo:")
+}
+
+/// Prints per-interface network counters and live up/download rates.
+fn print_network_info(sys: &System, net_io: &HashMap<String, NetIoRate>, include_loopback: bool) {
+    println!("\nNetwork Usage:");
+    let mut total_rx = 0u64;
+    let mut total_tx = 0u64;
+    let mut total_rx_rate = 0.0f64;
+    let mut total_tx_rate = 0.0f64;
+
+    for (name, data) in sys.networks().iter() {
+        if !include_loopback && is_loopback(name) {
+            continue;
+        }
+
+        let rx = data.total_received();
+        let tx = data.total_transmitted();
+        total_rx += rx;
+        total_tx += tx;
+
+        print!(
+            "Iface: {:<12} | Recv: {:>8} | Sent: {:>8}",
+            name,
+            format_size(rx),
+            format_size(tx),
+        );
+
+        if let Some(rate) = net_io.get(name) {
+            total_rx_rate += rate.rx_bytes_per_sec;
+            total_tx_rate += rate.tx_bytes_per_sec;
+            print!(
+                " | Down: {:>10} | Up: {:>10}",
+                format_rate(rate.rx_bytes_per_sec),
+                format_rate(rate.tx_bytes_per_sec),
+            );
+        }
+        println!();
+    }
+
+    println!(
+        "\nTotal Network Usage: Recv: {} | Sent: {}",
+        format_size(total_rx),
+        format_size(total_tx),
+    );
+    if !net_io.is_empty() {
+        println!(
+            "Total Network I/O:   Down: {} | Up: {}",
+            format_rate(total_rx_rate),
+            format_rate(total_tx_rate),
+        );
+    }
+}
+
+/// Prints the top `n` processes sorted by CPU usage or resident memory.
+///
+/// `sysinfo` reports per-process CPU usage summed across cores and normalized
+/// differently per platform, so each value is divided by the core count to keep
+/// it comparable with the aggregate CPU line in [`print_system_info`].
+fn print_process_info(sys: &System, n: usize, sort: SortBy) {
+    let core_count = sys.cpus().len().max(1) as f32;
+
+    println!("\nTop {} Processes (by {}):", n, match sort {
+        SortBy::Cpu => "CPU",
+        SortBy::Mem => "memory",
+    });
+
+    let mut procs: Vec<_> = sys
+        .processes()
+        .iter()
+        .map(|(pid, proc_)| (*pid, proc_.name().to_string(), proc_.cpu_usage() / core_count, proc_.memory()))
+        .collect();
+
+    match sort {
+        SortBy::Cpu => procs.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal)),
+        SortBy::Mem => procs.sort_by_key(|p| std::cmp::Reverse(p.3)),
+    }
+
+    for (pid, name, cpu, mem) in procs.into_iter().take(n) {
+        println!(
+            "PID: {:>7} | {:<20} | CPU: {:>6.2}% | Mem: {:>8}",
+            pid.to_string(),
+            name,
+            cpu,
+            format_size(mem),
+        );
+    }
+}
+
+/// Converts a Celsius reading to the requested unit, returning the value and
+/// its unit suffix.
+fn convert_temp(celsius: f32, fahrenheit: bool) -> (f32, &'static str) {
+    if fahrenheit {
+        (celsius * 9.0 / 5.0 + 32.0, "F")
+    } else {
+        (celsius, "C")
+    }
+}
+
+/// Prints thermal sensors with their current and, where available, critical
+/// temperatures. Sensors at or above their critical threshold are flagged
+/// inline. Falls back to the raw `/sys/class/thermal` zones on boards where
+/// `sysinfo` reports no components.
+fn print_thermal_info(sys: &System, fahrenheit: bool) {
+    println!("\nTemperatures:");
+
+    let components = sys.components();
+    if !components.is_empty() {
+        for component in components {
+            let critical = component.critical();
+            let (temp, unit) = convert_temp(component.temperature(), fahrenheit);
+
+            print!("Sensor: {:<20} | Temp: {:>6.1} °{}", component.label(), temp, unit);
+            if let Some(crit) = critical {
+                let (crit_conv, _) = convert_temp(crit, fahrenheit);
+                print!(" | Critical: {:>6.1} °{}", crit_conv, unit);
+                if component.temperature() >= crit {
+                    print!("  [CRITICAL]");
+                }
+            }
+            println!();
+        }
+        return;
+    }
+
+    // Fallback for minimal kernels that expose thermal zones but no components.
+    print_thermal_zones(fahrenheit);
+}
+
+/// Reads `/sys/class/thermal/thermal_zone*/temp` (millidegrees Celsius) and the
+/// matching `type` label directly, used when `sysinfo` reports no components.
+fn print_thermal_zones(fahrenheit: bool) {
+    let entries = match fs::read_dir("/sys/class/thermal") {
+        Ok(e) => e,
+        Err(_) => {
+            println!("(no thermal sensors available)");
+            return;
+        }
+    };
+
+    let mut zones: Vec<_> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .map(|n| n.starts_with("thermal_zone"))
+                .unwrap_or(false)
+        })
+        .collect();
+    zones.sort();
+
+    let mut found = false;
+    for zone in zones {
+        let millidegrees = match fs::read_to_string(zone.join("temp")) {
+            Ok(s) => match s.trim().parse::<i64>() {
+                Ok(v) => v,
+                Err(_) => continue,
+            },
+            Err(_) => continue,
+        };
+        let label = fs::read_to_string(zone.join("type"))
+            .map(|s| s.trim().to_string())
+            .unwrap_or_else(|_| {
+                zone.file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("unknown")
+                    .to_string()
+            });
+
+        let (temp, unit) = convert_temp(millidegrees as f32 / 1000.0, fahrenheit);
+        println!("Sensor: {:<20} | Temp: {:>6.1} °{}", label, temp, unit);
+        found = true;
+    }
+
+    if !found {
+        println!("(no thermal sensors available)");
+    }
 }
 
 #[cfg(test)]
@@ -130,6 +772,62 @@ mod tests {
         assert!(total_memory >= used_memory, "Total memory should be greater than or equal to used memory");
     }
 
+    #[test]
+    fn test_diskstats_device_name_strips_dev_prefix() {
+        assert_eq!(diskstats_device_name("/dev/sda1"), "sda1");
+        assert_eq!(diskstats_device_name("sda1"), "sda1");
+    }
+
+    #[test]
+    fn test_convert_temp() {
+        let (c, unit) = convert_temp(50.0, false);
+        assert_eq!(unit, "C");
+        assert!((c - 50.0).abs() < f32::EPSILON);
+
+        let (f, unit) = convert_temp(100.0, true);
+        assert_eq!(unit, "F");
+        assert!((f - 212.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert!("json".parse::<Format>().is_ok());
+        assert!("prometheus".parse::<Format>().is_ok());
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_prometheus_render_includes_core_metrics() {
+        let metrics = Metrics {
+            timestamp: "2024-01-01T00:00:00".to_string(),
+            cpu_percent: 12.5,
+            mem_used_bytes: 1024,
+            mem_total_bytes: 4096,
+            disks: vec![DiskMetric {
+                device: "/dev/sda1".to_string(),
+                used_bytes: 10,
+                free_bytes: 90,
+                total_bytes: 100,
+            }],
+        };
+        let out = metrics.to_prometheus();
+        assert!(out.contains("riscv_sysmon_cpu_usage_percent 12.50"));
+        assert!(out.contains("riscv_sysmon_disk_used_bytes{device=\"/dev/sda1\"} 10"));
+    }
+
+    #[test]
+    fn test_sortby_from_str() {
+        assert!("cpu".parse::<SortBy>().is_ok());
+        assert!("mem".parse::<SortBy>().is_ok());
+        assert!("bogus".parse::<SortBy>().is_err());
+    }
+
+    #[test]
+    fn test_is_loopback() {
+        assert!(is_loopback("lo"));
+        assert!(!is_loopback("eth0"));
+    }
+
     #[test]
     fn test_disk_usage_retrieval() {
         let mut sys = System::new_all();